@@ -1,5 +1,7 @@
-use crate::interface::{Matrix2x9, WithRestriction};
-use nalgebra::{DMatrix, Matrix3, RealField, Scalar};
+use crate::geo::{Line, Point};
+use crate::interface::{Matrix2x9, Vectorizable, WithRestriction};
+use nalgebra::{DMatrix, Matrix3, RealField, Scalar, Vector3};
+use num_traits::Float;
 
 pub fn generate_matrix_from_correspondences<T>(
     correspondences: Vec<&dyn WithRestriction<T>>,
@@ -23,12 +25,61 @@ where
     return matrix;
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HomographySolution<T: Scalar> {
     pub matrix: Matrix3<T>,
     pub value: T,
 }
 
+impl<T: RealField + Float> HomographySolution<T> {
+    /// Maps a point through the computed homography: `(x, y, 1) -> matrix · (x, y, 1)`,
+    /// dividing through by the resulting homogeneous coordinate.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to transform.
+    pub fn transform_point(&self, point: &Point<T>) -> Point<T> {
+        let v = point.to_vector();
+        let homogeneous = self.matrix * Vector3::new(v.x, v.y, T::one());
+
+        Point::new(homogeneous.x / homogeneous.z, homogeneous.y / homogeneous.z)
+    }
+
+    /// Maps a line through the computed homography via the inverse-transpose of
+    /// `matrix`, `(Hᵀ)⁻¹ · (a, b, c)`, since lines transform contravariantly with
+    /// respect to points.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The line to transform.
+    ///
+    /// # Returns
+    ///
+    /// `None` if `matrix` is singular, since the inverse-transpose used to map lines
+    /// doesn't exist in that case.
+    pub fn transform_line(&self, line: &Line<T>) -> Option<Line<T>> {
+        let inverse_transpose = self.matrix.try_inverse()?.transpose();
+        let v = inverse_transpose * line.to_vector();
+
+        Some(Line::new(v.x, v.y, v.z))
+    }
+}
+
+/// Solves the homogeneous least-squares system `A · h = 0` for `h`, returning the
+/// reshaped 3x3 homography and the smallest singular value (a measure of the residual).
+///
+/// nalgebra's SVD is thin: `v_t` only has `min(rows, cols)` rows, so when `matrix` has
+/// fewer than 9 rows (an under-determined system, e.g. the minimal 4-point DLT sample)
+/// the true null-space vector would be dropped rather than just the last row. Pad with
+/// zero rows first so `v_t` always has all 9 rows/columns and the null vector survives.
 pub fn solve<T: RealField + Copy>(matrix: DMatrix<T>) -> HomographySolution<T> {
+    let cols = matrix.ncols();
+    let matrix = if matrix.nrows() < cols {
+        matrix.resize_vertically(cols, T::zero())
+    } else {
+        matrix
+    };
+
     let svd = matrix.svd(false, true);
     let v_t = svd.v_t.unwrap();
     let n = v_t.nrows() - 1;
@@ -75,4 +126,96 @@ mod tests {
 
         let _solution = solve(matrix);
     }
+
+    #[test]
+    fn test_transform_point() {
+        // A 2x isotropic scale.
+        let solution = HomographySolution {
+            matrix: Matrix3::new(2., 0., 0., 0., 2., 0., 0., 0., 1.),
+            value: 0.,
+        };
+
+        let transformed = solution.transform_point(&Point::new(3., 4.));
+        assert_eq!(transformed.x, 6.);
+        assert_eq!(transformed.y, 8.);
+    }
+
+    #[test]
+    fn test_transform_line() {
+        // The identity homography leaves lines unchanged.
+        let solution = HomographySolution {
+            matrix: Matrix3::identity(),
+            value: 0.,
+        };
+
+        let line = Line::new(1., 2., 3.);
+        let transformed = solution.transform_line(&line).expect("identity is invertible");
+        assert_eq!(transformed.a, 1.);
+        assert_eq!(transformed.b, 2.);
+        assert_eq!(transformed.c, 3.);
+    }
+
+    #[test]
+    fn test_transform_line_returns_none_for_singular_matrix() {
+        // Rank-deficient matrix: no inverse-transpose exists to map lines through.
+        let solution = HomographySolution {
+            matrix: Matrix3::new(1., 2., 3., 2., 4., 6., 0., 0., 1.),
+            value: 0.,
+        };
+
+        let line = Line::new(1., 2., 3.);
+        assert!(solution.transform_line(&line).is_none());
+    }
+
+    #[test]
+    fn test_transform_point_lies_on_transform_line() {
+        let solution = HomographySolution {
+            matrix: Matrix3::new(1., 2., 3., 0., 1., 4., 0., 0., 1.),
+            value: 0.,
+        };
+
+        let p1 = Point::new(1., 2.);
+        let p2 = Point::new(5., -1.);
+        let line = Line::from_points(&p1, &p2);
+
+        let transformed_p1 = solution.transform_point(&p1);
+        let transformed_line = solution
+            .transform_line(&line)
+            .expect("matrix is invertible");
+
+        let residual = transformed_line.a * transformed_p1.x
+            + transformed_line.b * transformed_p1.y
+            + transformed_line.c;
+        assert!(residual.abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_transform_point_f64() {
+        let solution: HomographySolution<f64> = HomographySolution {
+            matrix: Matrix3::new(2., 0., 0., 0., 2., 0., 0., 0., 1.),
+            value: 0.,
+        };
+
+        let transformed = solution.transform_point(&Point::new(3., 4.));
+        assert_eq!(transformed.x, 6.);
+        assert_eq!(transformed.y, 8.);
+    }
+
+    // Requires the `serde` feature (and nalgebra's `serde-serialize` feature, for
+    // `Matrix3<T>: Serialize + Deserialize`) plus `serde_json` as a dev-dependency; run
+    // with `cargo test --features serde`.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_homography_solution_round_trips_through_serde() {
+        let solution = HomographySolution {
+            matrix: Matrix3::new(2., 0., 0., 0., 2., 0., 0., 0., 1.),
+            value: 0.5,
+        };
+
+        let json = serde_json::to_string(&solution).unwrap();
+        let restored: HomographySolution<f32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.matrix, solution.matrix);
+        assert_eq!(restored.value, solution.value);
+    }
 }
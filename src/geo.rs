@@ -2,11 +2,13 @@ use nalgebra::{ArrayStorage, Vector, Vector2, Vector3, U2, U3};
 
 use crate::interface::Vectorizable;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point<T = f32> {
     pub x: T,
     pub y: T,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line<T = f32> {
     pub a: T,
     pub b: T,
@@ -19,27 +21,79 @@ impl<T> Point<T> {
     }
 }
 
+impl<T: num_traits::Float> Point<T> {
+    /// Homogeneous cross product of `(self.x, self.y, 1)` and `(other.x, other.y, 1)`,
+    /// which is the line through both points (projective point/line duality).
+    pub fn cross(&self, other: &Point<T>) -> Line<T> {
+        Line::new(
+            self.y - other.y,
+            other.x - self.x,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Euclidean dot product with `other`.
+    pub fn dot(&self, other: &Point<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Squared Euclidean norm of the point, treated as a vector from the origin.
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Euclidean distance to `other`.
+    pub fn distance(&self, other: &Point<T>) -> T {
+        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2)).sqrt()
+    }
+}
+
 impl<T: num_traits::Float> Line<T> {
     pub fn new(a: T, b: T, c: T) -> Self {
         Line { a, b, c }
     }
 
+    /// The line through `p1` and `p2`, via the homogeneous cross product
+    /// `p2.cross(p1)` (see [`Point::cross`]).
     pub fn from_points(p1: &Point<T>, p2: &Point<T>) -> Line<T> {
-        let a = p2.y - p1.y;
-        let b = p1.x - p2.x;
-        let c = -a * p1.x - b * p1.y;
-        Line { a, b, c }
+        p2.cross(p1)
+    }
+
+    /// Signed distance from `point` to this line: `(a·x + b·y + c) / sqrt(a² + b²)`.
+    /// The sign indicates which of the two half-planes `point` lies in.
+    pub fn distance_to(&self, point: &Point<T>) -> T {
+        (self.a * point.x + self.b * point.y + self.c)
+            / (self.a * self.a + self.b * self.b).sqrt()
     }
 }
 
-impl Vectorizable<f32, U3, ArrayStorage<f32, 3, 1>> for Line {
-    fn to_vector(&self) -> Vector<f32, U3, ArrayStorage<f32, 3, 1>> {
+impl<T: num_traits::Float + nalgebra::Scalar + Copy> Line<T> {
+    /// Homogeneous cross product of this line and `other`, giving their (unnormalized)
+    /// intersection point. See [`Line::intersection`] for the normalized point.
+    pub fn cross(&self, other: &Line<T>) -> Vector3<T> {
+        Vector3::new(
+            self.b * other.c - self.c * other.b,
+            self.c * other.a - self.a * other.c,
+            self.a * other.b - self.b * other.a,
+        )
+    }
+
+    /// The intersection point of this line and `other`, i.e. `self.cross(other)`
+    /// normalized by its third (homogeneous) coordinate.
+    pub fn intersection(&self, other: &Line<T>) -> Point<T> {
+        let v = self.cross(other);
+        Point::new(v.x / v.z, v.y / v.z)
+    }
+}
+
+impl<T: nalgebra::Scalar + Copy> Vectorizable<T, U3, ArrayStorage<T, 3, 1>> for Line<T> {
+    fn to_vector(&self) -> Vector<T, U3, ArrayStorage<T, 3, 1>> {
         Vector3::new(self.a, self.b, self.c)
     }
 }
 
-impl Vectorizable<f32, U2, ArrayStorage<f32, 2, 1>> for Point {
-    fn to_vector(&self) -> Vector<f32, U2, ArrayStorage<f32, 2, 1>> {
+impl<T: nalgebra::Scalar + Copy> Vectorizable<T, U2, ArrayStorage<T, 2, 1>> for Point<T> {
+    fn to_vector(&self) -> Vector<T, U2, ArrayStorage<T, 2, 1>> {
         Vector2::new(self.x, self.y)
     }
 }
@@ -89,4 +143,88 @@ mod tests {
         assert_eq!(v[1], 2.0);
         assert_eq!(v[2], 3.0);
     }
+
+    #[test]
+    fn test_point_to_vector_f64() {
+        let p: Point<f64> = Point::new(1.0, 2.0);
+        let v = p.to_vector();
+        assert_eq!(v[0], 1.0);
+        assert_eq!(v[1], 2.0);
+    }
+
+    #[test]
+    fn test_point_dot_and_norm_squared() {
+        let p1 = Point::new(1.0, 2.0);
+        let p2 = Point::new(3.0, 4.0);
+        assert_eq!(p1.dot(&p2), 11.0);
+        assert_eq!(p1.norm_squared(), 5.0);
+    }
+
+    #[test]
+    fn test_point_distance() {
+        let p1 = Point::new(0.0, 0.0);
+        let p2 = Point::new(3.0, 4.0);
+        assert_eq!(p1.distance(&p2), 5.0);
+    }
+
+    #[test]
+    fn test_point_cross_matches_from_points() {
+        let p1 = Point::new(1.0, 2.0);
+        let p2 = Point::new(3.0, 4.0);
+
+        let l = p2.cross(&p1);
+        assert_eq!(l.a, 2.0);
+        assert_eq!(l.b, -2.0);
+        assert_eq!(l.c, 2.0);
+    }
+
+    #[test]
+    fn test_line_intersection() {
+        let l1 = Line::new(1.0, 0.0, 0.0); // x = 0
+        let l2 = Line::new(0.0, 1.0, 0.0); // y = 0
+
+        let intersection = l1.intersection(&l2);
+        assert_eq!(intersection.x, 0.0);
+        assert_eq!(intersection.y, 0.0);
+    }
+
+    #[test]
+    fn test_line_distance_to() {
+        let line = Line::new(1.0, 0.0, 0.0); // x = 0
+        let point = Point::new(3.0, 4.0);
+        assert_eq!(line.distance_to(&point), 3.0);
+    }
+
+    #[test]
+    fn test_point_lies_on_line_through_it() {
+        let p1 = Point::new(1.0, 2.0);
+        let p2 = Point::new(3.0, 4.0);
+        let line = Line::from_points(&p1, &p2);
+
+        let residual = line.distance_to(&p1);
+        assert!(residual.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transformed_point_lies_on_transformed_line() {
+        use crate::functions::HomographySolution;
+        use nalgebra::Matrix3;
+
+        let solution: HomographySolution<f64> = HomographySolution {
+            matrix: Matrix3::new(1., 2., 3., 0., 1., 4., 0., 0., 1.),
+            value: 0.,
+        };
+
+        let p1 = Point::new(1., 2.);
+        let p2 = Point::new(5., -1.);
+        let line = Line::from_points(&p1, &p2);
+
+        let transformed_p1 = solution.transform_point(&p1);
+        let transformed_line = solution
+            .transform_line(&line)
+            .expect("matrix is invertible");
+
+        let residual = transformed_line.distance_to(&transformed_p1);
+        assert!(residual.abs() < 1e-9);
+    }
 }
@@ -0,0 +1,137 @@
+//! A small text-based format for correspondence sets, in the spirit of nalgebra's
+//! optional `io` feature. It lets callers load a [`HomographyComputation`] from text,
+//! e.g. a shared, reproducible test case, without wiring up the structs by hand. Only
+//! parsing is provided; this module has no writer for the format.
+//!
+//! One record per line:
+//!
+//! ```text
+//! P 148 337 -> 0 0
+//! L 1 2 3 -> 4 5 6
+//! ```
+//!
+//! `P x y -> xp yp` adds a point correspondence, `L a b c -> ap bp cp` adds a line
+//! correspondence. Blank lines and lines starting with `#` are ignored.
+
+use std::fmt;
+
+use crate::geo::{Line, Point};
+use crate::HomographyComputation;
+
+/// An error encountered while parsing a correspondence file, carrying the 1-based line
+/// number of the offending record.
+#[derive(Debug)]
+pub struct ParseError(pub usize);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid correspondence record at line {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a correspondence file (see the [module docs](self)) into a populated
+/// [`HomographyComputation`].
+///
+/// # Arguments
+///
+/// * `input` - The contents of the correspondence file.
+///
+/// # Returns
+///
+/// The populated computation, or a [`ParseError`] pointing at the first line that
+/// doesn't match the `P`/`L` record format.
+pub fn load_correspondences(input: &str) -> Result<HomographyComputation<f32>, ParseError> {
+    let mut hc = HomographyComputation::new();
+
+    for (i, raw_line) in input.lines().enumerate() {
+        let line_number = i + 1;
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let first_char = line.chars().next().ok_or(ParseError(line_number))?;
+        let (kind, rest) = line.split_at(first_char.len_utf8());
+        let (lhs, rhs) = rest
+            .trim()
+            .split_once("->")
+            .ok_or(ParseError(line_number))?;
+
+        let lhs = parse_numbers(lhs).ok_or(ParseError(line_number))?;
+        let rhs = parse_numbers(rhs).ok_or(ParseError(line_number))?;
+
+        match (kind, lhs.as_slice(), rhs.as_slice()) {
+            ("P", &[x1, y1], &[x2, y2]) => {
+                hc.add_point_correspondence(Point::new(x1, y1), Point::new(x2, y2));
+            }
+            ("L", &[a1, b1, c1], &[a2, b2, c2]) => {
+                hc.add_line_correspondence(Line::new(a1, b1, c1), Line::new(a2, b2, c2));
+            }
+            _ => return Err(ParseError(line_number)),
+        }
+    }
+
+    Ok(hc)
+}
+
+/// Parses a whitespace-separated list of floats, returning `None` if any token fails
+/// to parse.
+fn parse_numbers(s: &str) -> Option<Vec<f32>> {
+    s.split_whitespace().map(|tok| tok.parse().ok()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_point_correspondence() {
+        let hc = load_correspondences("P 148 337 -> 0 0").unwrap();
+        assert_eq!(hc.point_correspondences.len(), 1);
+        assert_eq!(hc.point_correspondences[0].p1.x, 148.);
+        assert_eq!(hc.point_correspondences[0].p2.y, 0.);
+    }
+
+    #[test]
+    fn test_load_line_correspondence() {
+        let hc = load_correspondences("L 1 2 3 -> 4 5 6").unwrap();
+        assert_eq!(hc.line_correspondences.len(), 1);
+        assert_eq!(hc.line_correspondences[0].l1.c, 3.);
+        assert_eq!(hc.line_correspondences[0].l2.a, 4.);
+    }
+
+    #[test]
+    fn test_load_ignores_blank_and_comment_lines() {
+        let input = "\n# a comment\nP 0 0 -> 1 1\n";
+        let hc = load_correspondences(input).unwrap();
+        assert_eq!(hc.point_correspondences.len(), 1);
+    }
+
+    #[test]
+    fn test_load_multiple_records() {
+        let input = "P 148 337 -> 0 0\nP 131 516 -> 0 60\nL 1 2 3 -> 4 5 6";
+        let hc = load_correspondences(input).unwrap();
+        assert_eq!(hc.point_correspondences.len(), 2);
+        assert_eq!(hc.line_correspondences.len(), 1);
+    }
+
+    #[test]
+    fn test_load_rejects_invalid_line() {
+        let err = load_correspondences("X 1 2 -> 3 4").unwrap_err();
+        assert_eq!(err.0, 1);
+    }
+
+    #[test]
+    fn test_load_rejects_multibyte_leading_char_without_panicking() {
+        let err = load_correspondences("é 1 2 -> 3 4").unwrap_err();
+        assert_eq!(err.0, 1);
+    }
+
+    #[test]
+    fn test_load_rejects_wrong_arity() {
+        let err = load_correspondences("P 1 2 3 -> 4 5").unwrap_err();
+        assert_eq!(err.0, 1);
+    }
+}
@@ -53,31 +53,173 @@
 pub mod functions;
 pub mod geo;
 pub mod interface;
+pub mod io;
 pub mod pairs;
 
-use crate::functions::{solve, HomographySolution};
+use crate::functions::{generate_matrix_from_correspondences, solve, HomographySolution};
 use crate::geo::{Line, Point};
 use crate::interface::{Matrix2x9, WithRestriction};
 use crate::pairs::LinePair;
-use nalgebra::{DMatrix, RealField, Scalar};
+use nalgebra::{ComplexField, DMatrix, Matrix3, RealField, Scalar, Vector3};
+use num_traits::Float;
 
 use crate::pairs::PointPair;
 
 /// Represents restrictions for a homography computation.
 /// The restrictions are represented as a vector of 2x9 matrices.
+///
+/// When built from normalized correspondences (see
+/// [`HomographyComputation::get_restrictions`]), also carries the `(T, T')` pair of
+/// isotropic normalization transforms so that [`HomographyRestrictions::compute`] can
+/// denormalize the solution back into the original coordinate system.
 pub struct HomographyRestrictions<T: Scalar> {
     restrictions: Vec<Matrix2x9<T>>,
+    normalization: Option<(Matrix3<T>, Matrix3<T>)>,
+}
+
+/// Computes the isotropic (Hartley) normalization transform for a set of points: a
+/// similarity matrix that translates the centroid of `points` to the origin and scales
+/// the points so that their mean distance to the origin is `sqrt(2)`. Conditioning the
+/// points this way before feeding them into the DLT matrix keeps its entries at
+/// comparable magnitudes, which is what makes the subsequent SVD numerically stable.
+fn normalization_transform<T: RealField + Float>(points: &[Point<T>]) -> Matrix3<T> {
+    let n = T::from(points.len()).unwrap();
+    let (sum_x, sum_y) = points
+        .iter()
+        .fold((T::zero(), T::zero()), |(sx, sy), p| (sx + p.x, sy + p.y));
+    let cx = sum_x / n;
+    let cy = sum_y / n;
+
+    let mean_distance = points.iter().fold(T::zero(), |acc, p| {
+        acc + ComplexField::sqrt(
+            Float::powi(p.x - cx, 2) + Float::powi(p.y - cy, 2),
+        )
+    }) / n;
+
+    let scale = if mean_distance > T::zero() {
+        ComplexField::sqrt(T::from(2.0).unwrap()) / mean_distance
+    } else {
+        T::one()
+    };
+
+    Matrix3::new(
+        scale,
+        T::zero(),
+        -scale * cx,
+        T::zero(),
+        scale,
+        -scale * cy,
+        T::zero(),
+        T::zero(),
+        T::one(),
+    )
+}
+
+/// Applies a 3x3 homogeneous transform to a point: `(x, y, 1) -> t · (x, y, 1)`,
+/// dividing through by the resulting homogeneous coordinate.
+fn apply_matrix_to_point<T: RealField + Float>(t: &Matrix3<T>, p: &Point<T>) -> Point<T> {
+    let v = t * Vector3::new(p.x, p.y, T::one());
+    Point::new(v.x / v.z, v.y / v.z)
+}
+
+/// Applies a normalization transform to a line via the inverse-transpose of `t`, since
+/// lines transform contravariantly with respect to points.
+fn normalize_line<T: RealField + Float>(t: &Matrix3<T>, l: &Line<T>) -> Line<T> {
+    let t_inv_t = t
+        .try_inverse()
+        .expect("normalization transform is always invertible")
+        .transpose();
+    let v = t_inv_t * Vector3::new(l.a, l.b, l.c);
+    Line::new(v.x, v.y, v.z)
 }
 
 /// Represents a homography computation, which involves finding a transformation matrix
 /// that maps points and lines from one coordinate system to another.
-pub struct HomographyComputation {
-    point_correspondences: Vec<PointPair>,
-    line_correspondences: Vec<LinePair>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HomographyComputation<T = f32> {
+    point_correspondences: Vec<PointPair<T>>,
+    line_correspondences: Vec<LinePair<T>>,
+}
+
+/// Parameters controlling [`HomographyComputation::compute_ransac`].
+pub struct RansacParams {
+    /// Maximum symmetric transfer error (in squared coordinate units) below which a
+    /// correspondence is counted as an inlier.
+    pub threshold: f32,
+    /// Desired probability `p` of having drawn at least one outlier-free minimal sample.
+    pub confidence: f32,
+    /// Hard cap on the number of iterations, used as the starting point for the
+    /// adaptively shrunk iteration count.
+    pub max_iterations: usize,
+}
+
+impl Default for RansacParams {
+    fn default() -> Self {
+        RansacParams {
+            threshold: 3.,
+            confidence: 0.99,
+            max_iterations: 2000,
+        }
+    }
+}
+
+/// The result of [`HomographyComputation::compute_ransac`]: the solution refit on all
+/// inliers, together with a mask of which point correspondences (in the order they were
+/// added) were judged inliers.
+pub struct RansacSolution<T: Scalar> {
+    pub solution: HomographySolution<T>,
+    pub inliers: Vec<bool>,
+}
+
+/// Returns `true` if any three of the four points are near-collinear, which makes a
+/// minimal DLT sample degenerate (the 8x9 restriction matrix loses rank).
+fn is_degenerate_sample(points: &[Point<f32>; 4]) -> bool {
+    let is_collinear = |a: &Point<f32>, b: &Point<f32>, c: &Point<f32>| -> bool {
+        let v1x = b.x - a.x;
+        let v1y = b.y - a.y;
+        let v2x = c.x - a.x;
+        let v2y = c.y - a.y;
+
+        let cross = v1x * v2y - v1y * v2x;
+        let norm = (v1x * v1x + v1y * v1y).sqrt() * (v2x * v2x + v2y * v2y).sqrt();
+
+        norm < 1e-6 || (cross / norm).abs() < 1e-3
+    };
+
+    is_collinear(&points[0], &points[1], &points[2])
+        || is_collinear(&points[0], &points[1], &points[3])
+        || is_collinear(&points[0], &points[2], &points[3])
+        || is_collinear(&points[1], &points[2], &points[3])
+}
+
+/// Draws 4 distinct correspondence indices out of `0..n` uniformly at random.
+fn sample_four_indices(n: usize, rng: &mut impl rand::Rng) -> [usize; 4] {
+    let mut chosen = std::collections::HashSet::with_capacity(4);
+    while chosen.len() < 4 {
+        chosen.insert(rng.gen_range(0..n));
+    }
+
+    let mut indices = [0usize; 4];
+    for (slot, idx) in indices.iter_mut().zip(chosen.into_iter()) {
+        *slot = idx;
+    }
+    indices
+}
+
+/// Symmetric transfer error of a single correspondence under `h`/`h_inv`:
+/// `‖H·x − x'‖² + ‖H⁻¹·x' − x‖²`.
+fn symmetric_transfer_error(h: &Matrix3<f32>, h_inv: &Matrix3<f32>, pair: &PointPair) -> f32 {
+    let projected = apply_matrix_to_point(h, &pair.p1);
+    let back_projected = apply_matrix_to_point(h_inv, &pair.p2);
+
+    let forward = (projected.x - pair.p2.x).powi(2) + (projected.y - pair.p2.y).powi(2);
+    let backward = (back_projected.x - pair.p1.x).powi(2) + (back_projected.y - pair.p1.y).powi(2);
+
+    forward + backward
 }
 
 /// Implementation of HomographyComputation, which represents a computation of homography.
-impl HomographyComputation {
+impl<T: RealField + Float> HomographyComputation<T> {
     /// Creates a new instance of HomographyComputation.
     ///
     /// # Returns
@@ -96,7 +238,7 @@ impl HomographyComputation {
     ///
     /// * `p1` - The first point in the correspondence.
     /// * `p2` - The second point in the correspondence.
-    pub fn add_point_correspondence(&mut self, p1: Point, p2: Point) {
+    pub fn add_point_correspondence(&mut self, p1: Point<T>, p2: Point<T>) {
         self.point_correspondences.push(PointPair { p1, p2 });
     }
 
@@ -106,18 +248,82 @@ impl HomographyComputation {
     ///
     /// * `l1` - The first line in the correspondence.
     /// * `l2` - The second line in the correspondence.
-    pub fn add_line_correspondence(&mut self, l1: Line, l2: Line) {
+    pub fn add_line_correspondence(&mut self, l1: Line<T>, l2: Line<T>) {
         self.line_correspondences.push(LinePair { l1, l2 });
     }
 
     /// Gets the restrictions for the homography computation.
     ///
+    /// Applies an isotropic (Hartley) normalization pass to the correspondences before
+    /// building the restrictions, which keeps the DLT matrix well conditioned for the
+    /// SVD solve. The normalization is undone transparently when
+    /// [`HomographyRestrictions::compute`] is called, so callers get back a solution in
+    /// the original coordinate system. Use [`HomographyComputation::get_restrictions_raw`]
+    /// to opt out.
+    ///
     /// # Returns
     ///
     /// The restrictions for the homography computation.
-    pub fn get_restrictions(&self) -> HomographyRestrictions<f32> {
+    pub fn get_restrictions(&self) -> HomographyRestrictions<T> {
+        let source_points: Vec<Point<T>> = self
+            .point_correspondences
+            .iter()
+            .map(|pair| Point::new(pair.p1.x, pair.p1.y))
+            .collect();
+        let dest_points: Vec<Point<T>> = self
+            .point_correspondences
+            .iter()
+            .map(|pair| Point::new(pair.p2.x, pair.p2.y))
+            .collect();
+
+        if source_points.is_empty() {
+            // Nothing to anchor a centroid/scale on; fall back to the raw correspondences.
+            return self.get_restrictions_raw();
+        }
+
+        let t = normalization_transform(&source_points);
+        let t_prime = normalization_transform(&dest_points);
+
+        let mut restrictions = HomographyRestrictions {
+            restrictions: Vec::new(),
+            normalization: Some((t, t_prime)),
+        };
+
+        for pair in &self.point_correspondences {
+            let normalized = PointPair {
+                p1: apply_matrix_to_point(&t, &pair.p1),
+                p2: apply_matrix_to_point(&t_prime, &pair.p2),
+            };
+            restrictions
+                .restrictions
+                .push(normalized.generate_restriction());
+        }
+
+        for pair in &self.line_correspondences {
+            let normalized = LinePair {
+                l1: normalize_line(&t, &pair.l1),
+                l2: normalize_line(&t_prime, &pair.l2),
+            };
+            restrictions
+                .restrictions
+                .push(normalized.generate_restriction());
+        }
+
+        restrictions
+    }
+
+    /// Gets the restrictions for the homography computation without the Hartley
+    /// normalization pass that [`HomographyComputation::get_restrictions`] applies by
+    /// default.
+    ///
+    /// # Returns
+    ///
+    /// The restrictions for the homography computation, built directly from the raw
+    /// correspondences.
+    pub fn get_restrictions_raw(&self) -> HomographyRestrictions<T> {
         let mut restrictions = HomographyRestrictions {
             restrictions: Vec::new(),
+            normalization: None,
         };
 
         for pair in &self.point_correspondences {
@@ -132,6 +338,124 @@ impl HomographyComputation {
     }
 }
 
+/// RANSAC estimation is implemented for `f32` only: scoring and sample degeneracy
+/// checks are cheap single-precision arithmetic, and the adaptive iteration count has no
+/// need for the extra precision that [`HomographyComputation<T>`] otherwise offers.
+impl HomographyComputation<f32> {
+    /// Robustly estimates a homography from the point correspondences using RANSAC,
+    /// which tolerates mismatched correspondences that would otherwise corrupt the plain
+    /// least-squares [`HomographyComputation::get_restrictions`]/[`HomographyRestrictions::compute`]
+    /// path.
+    ///
+    /// Repeatedly draws a minimal sample of 4 point correspondences, solves the 8x9 DLT
+    /// system for a candidate homography, and scores it against every correspondence
+    /// using the symmetric transfer error. The iteration count is adapted down as the
+    /// best inlier ratio improves, following `N = log(1-p) / log(1-w⁴)`. The final
+    /// solution is refit (with Hartley normalization, see
+    /// [`HomographyComputation::get_restrictions`]) on every inlier of the best sample
+    /// found. Line correspondences are not used by RANSAC.
+    ///
+    /// # Returns
+    ///
+    /// `None` if fewer than 4 point correspondences were added, or if no sample ever
+    /// produced at least 4 inliers. Otherwise, the refit solution and its inlier mask.
+    pub fn compute_ransac(&self, params: RansacParams) -> Option<RansacSolution<f32>> {
+        let n = self.point_correspondences.len();
+        if n < 4 {
+            return None;
+        }
+
+        let mut rng = rand::thread_rng();
+        let mut best_inliers: Vec<bool> = Vec::new();
+        let mut best_inlier_count = 0usize;
+
+        let mut max_iterations = params.max_iterations;
+        let mut iteration = 0;
+
+        while iteration < max_iterations {
+            iteration += 1;
+
+            let sample = sample_four_indices(n, &mut rng);
+            let sample_points: [Point<f32>; 4] = std::array::from_fn(|i| {
+                let p = &self.point_correspondences[sample[i]].p1;
+                Point::new(p.x, p.y)
+            });
+
+            if is_degenerate_sample(&sample_points) {
+                continue;
+            }
+
+            let sample_pairs: Vec<PointPair> = sample
+                .iter()
+                .map(|&i| {
+                    let pair = &self.point_correspondences[i];
+                    PointPair {
+                        p1: Point::new(pair.p1.x, pair.p1.y),
+                        p2: Point::new(pair.p2.x, pair.p2.y),
+                    }
+                })
+                .collect();
+
+            let restriction_refs: Vec<&dyn WithRestriction<f32>> = sample_pairs
+                .iter()
+                .map(|p| p as &dyn WithRestriction<f32>)
+                .collect();
+            let matrix = generate_matrix_from_correspondences(restriction_refs);
+            let candidate = solve(matrix);
+
+            let Some(candidate_inv) = candidate.matrix.try_inverse() else {
+                continue;
+            };
+
+            let mut inliers = vec![false; n];
+            let mut inlier_count = 0;
+            for (i, pair) in self.point_correspondences.iter().enumerate() {
+                if symmetric_transfer_error(&candidate.matrix, &candidate_inv, pair)
+                    < params.threshold
+                {
+                    inliers[i] = true;
+                    inlier_count += 1;
+                }
+            }
+
+            if inlier_count > best_inlier_count {
+                best_inlier_count = inlier_count;
+                best_inliers = inliers;
+
+                let w = best_inlier_count as f32 / n as f32;
+                let denominator = (1. - w.powi(4)).ln();
+                if denominator < 0. {
+                    let adaptive = ((1. - params.confidence).ln() / denominator).ceil();
+                    if adaptive.is_finite() {
+                        max_iterations = max_iterations.min((adaptive as usize).max(iteration));
+                    }
+                }
+            }
+        }
+
+        if best_inlier_count < 4 {
+            return None;
+        }
+
+        let mut refit = HomographyComputation::new();
+        for (pair, &is_inlier) in self.point_correspondences.iter().zip(best_inliers.iter()) {
+            if is_inlier {
+                refit.add_point_correspondence(
+                    Point::new(pair.p1.x, pair.p1.y),
+                    Point::new(pair.p2.x, pair.p2.y),
+                );
+            }
+        }
+
+        let solution = refit.get_restrictions().compute();
+
+        Some(RansacSolution {
+            solution,
+            inliers: best_inliers,
+        })
+    }
+}
+
 /// A implementation for computing homography restrictions.
 ///
 /// # Example
@@ -167,6 +491,10 @@ impl HomographyComputation {
 impl<T: Scalar + Copy + num_traits::Zero + RealField> HomographyRestrictions<T> {
     /// Computes the homography solution based on the restrictions.
     ///
+    /// If the restrictions were built from normalized correspondences, the raw SVD
+    /// solution `Ĥ` is denormalized back into the original coordinate system as
+    /// `H = T'⁻¹ · Ĥ · T` before being returned.
+    ///
     /// # Returns
     ///
     /// The computed homography solution.
@@ -179,7 +507,21 @@ impl<T: Scalar + Copy + num_traits::Zero + RealField> HomographyRestrictions<T>
             matrix.set_row(i * 2 + 1, &m.row(1));
         }
 
-        solve(matrix)
+        let solution = solve(matrix);
+
+        match &self.normalization {
+            Some((t, t_prime)) => {
+                let t_prime_inv = t_prime
+                    .try_inverse()
+                    .expect("normalization transform is always invertible");
+
+                HomographySolution {
+                    matrix: t_prime_inv * solution.matrix * t,
+                    value: solution.value,
+                }
+            }
+            None => solution,
+        }
     }
 }
 
@@ -258,4 +600,159 @@ mod tests {
         assert_eq!(solution.matrix.nrows(), 3);
         assert_eq!(solution.matrix.ncols(), 3);
     }
+
+    #[test]
+    fn test_normalization_transform_centers_and_scales() {
+        let points = vec![
+            Point::new(0., 0.),
+            Point::new(2., 0.),
+            Point::new(2., 2.),
+            Point::new(0., 2.),
+        ];
+
+        let t = normalization_transform(&points);
+        let normalized: Vec<Point<f32>> = points
+            .iter()
+            .map(|p| apply_matrix_to_point(&t, p))
+            .collect();
+
+        let centroid_x = normalized.iter().map(|p| p.x).sum::<f32>() / normalized.len() as f32;
+        let centroid_y = normalized.iter().map(|p| p.y).sum::<f32>() / normalized.len() as f32;
+        assert!(centroid_x.abs() < 1e-5);
+        assert!(centroid_y.abs() < 1e-5);
+
+        let mean_distance = normalized
+            .iter()
+            .map(|p| (p.x * p.x + p.y * p.y).sqrt())
+            .sum::<f32>()
+            / normalized.len() as f32;
+        assert!((mean_distance - 2f32.sqrt()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_get_restrictions_raw_skips_normalization() {
+        let mut hc = HomographyComputation::new();
+        hc.add_point_correspondence(Point::new(148., 337.), Point::new(0., 0.));
+        hc.add_point_correspondence(Point::new(131., 516.), Point::new(0., 60.));
+
+        let restrictions = hc.get_restrictions_raw();
+        assert!(restrictions.normalization.is_none());
+    }
+
+    #[test]
+    fn test_normalized_compute_matches_raw_compute() {
+        let mut hc = HomographyComputation::new();
+        let p1 = Point::new(148., 337.);
+        let p2 = Point::new(131., 516.);
+        let p3 = Point::new(321., 486.);
+        let p4 = Point::new(332., 370.);
+
+        let p1p = Point::new(0., 0.);
+        let p2p = Point::new(0., 60.);
+        let p3p = Point::new(80., 60.);
+        let p4p = Point::new(80., 0.);
+
+        hc.add_point_correspondence(p1, p1p);
+        hc.add_point_correspondence(p2, p2p);
+        hc.add_point_correspondence(p3, p3p);
+        hc.add_point_correspondence(p4, p4p);
+
+        let normalized_solution = hc.get_restrictions().compute();
+        let raw_solution = hc.get_restrictions_raw().compute();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let a = normalized_solution.matrix[(i, j)] / normalized_solution.matrix[(2, 2)];
+                let b = raw_solution.matrix[(i, j)] / raw_solution.matrix[(2, 2)];
+                assert!((a - b).abs() < 1e-2, "mismatch at ({i},{j}): {a} vs {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_compute_ransac_returns_none_with_too_few_correspondences() {
+        let mut hc = HomographyComputation::new();
+        hc.add_point_correspondence(Point::new(0., 0.), Point::new(0., 0.));
+        hc.add_point_correspondence(Point::new(1., 0.), Point::new(2., 0.));
+
+        assert!(hc.compute_ransac(RansacParams::default()).is_none());
+    }
+
+    #[test]
+    fn test_compute_ransac_rejects_outliers() {
+        let mut hc = HomographyComputation::new();
+
+        // A clean 2x isotropic scale: every correspondence here is exact, and no three
+        // inlier source points are collinear, so every all-inlier sample is usable.
+        hc.add_point_correspondence(Point::new(0., 0.), Point::new(0., 0.));
+        hc.add_point_correspondence(Point::new(1., 0.), Point::new(2., 0.));
+        hc.add_point_correspondence(Point::new(1., 1.), Point::new(2., 2.));
+        hc.add_point_correspondence(Point::new(0., 1.), Point::new(0., 2.));
+        hc.add_point_correspondence(Point::new(0.5, 0.25), Point::new(1., 0.5));
+        // Outlier: ignores the scale mapping entirely.
+        hc.add_point_correspondence(Point::new(0.3, 0.7), Point::new(50., 50.));
+
+        let result = hc
+            .compute_ransac(RansacParams {
+                threshold: 1e-2,
+                confidence: 0.99,
+                max_iterations: 500,
+            })
+            .expect("ransac should find a solution");
+
+        assert_eq!(result.inliers, vec![true, true, true, true, true, false]);
+    }
+
+    #[test]
+    fn test_compute_homography_solution_f64() {
+        let mut hc: HomographyComputation<f64> = HomographyComputation::new();
+        hc.add_point_correspondence(Point::new(148., 337.), Point::new(0., 0.));
+        hc.add_point_correspondence(Point::new(131., 516.), Point::new(0., 60.));
+        hc.add_point_correspondence(Point::new(321., 486.), Point::new(80., 60.));
+        hc.add_point_correspondence(Point::new(332., 370.), Point::new(80., 0.));
+
+        let solution = hc.get_restrictions().compute();
+
+        assert_eq!(solution.matrix.nrows(), 3);
+        assert_eq!(solution.matrix.ncols(), 3);
+    }
+
+    #[test]
+    fn test_compute_recovers_exact_minimal_homography() {
+        // Exactly 4 point correspondences and no lines: the DLT matrix has only 8 rows,
+        // one short of the 9 columns. `solve` must still recover the true null vector
+        // rather than the right-singular vector of the smallest *nonzero* singular value.
+        let mut hc = HomographyComputation::new();
+        hc.add_point_correspondence(Point::new(0., 0.), Point::new(0., 0.));
+        hc.add_point_correspondence(Point::new(1., 0.), Point::new(2., 0.));
+        hc.add_point_correspondence(Point::new(1., 1.), Point::new(2., 2.));
+        hc.add_point_correspondence(Point::new(0., 1.), Point::new(0., 2.));
+
+        let solution = hc.get_restrictions().compute();
+
+        let transformed = solution.transform_point(&Point::new(1., 1.));
+        assert!((transformed.x - 2.).abs() < 1e-4);
+        assert!((transformed.y - 2.).abs() < 1e-4);
+    }
+
+    // Requires the `serde` feature, which in turn needs nalgebra's own
+    // `serde-serialize` feature for `Matrix3<T>: Serialize + Deserialize` and
+    // `serde_json` as a dev-dependency to round-trip through; run with
+    // `cargo test --features serde`.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_homography_computation_round_trips_through_serde() {
+        let mut hc = HomographyComputation::new();
+        hc.add_point_correspondence(Point::new(148., 337.), Point::new(0., 0.));
+        hc.add_line_correspondence(Line::new(1., 2., 3.), Line::new(4., 5., 6.));
+
+        let json = serde_json::to_string(&hc).unwrap();
+        let restored: HomographyComputation<f32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.point_correspondences.len(), 1);
+        assert_eq!(restored.point_correspondences[0].p1.x, 148.);
+        assert_eq!(restored.point_correspondences[0].p2.y, 0.);
+        assert_eq!(restored.line_correspondences.len(), 1);
+        assert_eq!(restored.line_correspondences[0].l2.c, 6.);
+    }
 }